@@ -1,18 +1,39 @@
 use std::fmt::{Display, Formatter};
-use std::io::Read;
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::Duration;
 
 use bincode::config::standard;
 use bincode::error::DecodeError;
-use bincode::decode_from_std_read;
 use cu29::copperlist::CopperList;
 use cu29_intern_strs::read_interned_strings;
+// `--min-severity` is unimplemented. `cu29_log::CuLogEntry` carries no severity field,
+// and the source for `cu29_log` is not vendored anywhere in this tree (it's an external
+// dependency, not a local crate this repo snapshot builds), so there is no file here to
+// add a `level`/`CuLogLevel` to — doing so would mean fabricating that crate's source
+// from scratch, which this repo's sources don't contain. Rather than reference a field
+// that doesn't exist (which wouldn't compile) or silently ignore the flag (which would
+// mislead callers), `LogFilter::from_args` rejects `--min-severity` outright until
+// `cu29_log` actually grows that field upstream.
+//
+// `Glog` below also wants the call site's real file/line, and `Json` below wants each
+// log parameter's real name — both live in `cu29_intern_strs`'s interned string table
+// (keyed by `msg_index`), but `read_interned_strings` only exposes format strings
+// today, not a richer per-call-site record with file/line, and there's no way from
+// here to resolve a parameter's name index back to a string either. Extending
+// `cu29_intern_strs` to expose that also lives outside `cu29_export`, so `Glog` renders
+// placeholder file/line and `Json` keys params positionally until it does.
 use cu29_log::{rebuild_logline, CuLogEntry};
 use cu29_traits::{CuError, CuResult, UnifiedLogType};
 
 use clap::{Parser, Subcommand, ValueEnum};
 use cu29_traits::CopperListPayload;
 use cu29_unifiedlog::{UnifiedLogger, UnifiedLoggerBuilder, UnifiedLoggerIOReader};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{Map, Value};
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub enum ExportFormat {
@@ -29,24 +50,325 @@ impl Display for ExportFormat {
     }
 }
 
+/// Controls whether `textlog_dump` emits ANSI color escapes around each log line.
+#[derive(Copy, Clone, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorMode {
+    /// Colorize when stdout is a TTY, plain text otherwise.
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl Display for ColorMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorMode::Auto => write!(f, "auto"),
+            ColorMode::Always => write!(f, "always"),
+            ColorMode::Never => write!(f, "never"),
+        }
+    }
+}
+
+const ANSI_RESET: &str = "\x1B[1;0m";
+
+/// Accent used to colorize a `Copper`-format line. `cu29_log::CuLogEntry` doesn't carry
+/// a per-entry severity (see the note above the `cu29_log` import), so every line gets
+/// this one accent rather than being keyed by level as originally envisioned.
+const LINE_ACCENT: &str = "\x1B[36m";
+
+/// Writes rebuilt log lines to stdout, wrapping each `Copper`-format line in `LINE_ACCENT`
+/// and resetting it afterwards when writing to a TTY (or when forced via `--color`);
+/// `Glog`/`Json` lines are always written plain.
+struct TextLogWriter {
+    use_color: bool,
+}
+
+impl TextLogWriter {
+    fn new(mode: ColorMode) -> Self {
+        let use_color = match mode {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => io::stdout().is_terminal(),
+        };
+        Self { use_color }
+    }
+
+    fn write_line(&self, formatted: &str, format: LogFormat) {
+        if should_colorize(format, self.use_color) {
+            println!("{LINE_ACCENT}{formatted}{ANSI_RESET}");
+        } else {
+            println!("{formatted}");
+        }
+    }
+}
+
+/// The structured output layout `textlog_dump` renders each entry into, so rebuilt log
+/// lines can be consumed by existing log-ingestion tooling.
+#[derive(Copy, Clone, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// The original `Culog: [<time>] <msg>` layout.
+    #[default]
+    Copper,
+    /// Google glog's `Lmmdd HH:MM:SS.micros thread file:line] msg` layout.
+    Glog,
+    /// One JSON object per line with `time`, `msg_index`, the resolved message, and params.
+    Json,
+}
+
+impl Display for LogFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogFormat::Copper => write!(f, "copper"),
+            LogFormat::Glog => write!(f, "glog"),
+            LogFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl LogFormat {
+    fn formatter(&self) -> Box<dyn LogLineFormatter> {
+        match self {
+            LogFormat::Copper => Box::new(CopperLineFormat),
+            LogFormat::Glog => Box::new(GlogLineFormat),
+            LogFormat::Json => Box::new(JsonLineFormat),
+        }
+    }
+}
+
+/// Whether a rendered line should be wrapped in ANSI color escapes. Only `Copper` is a
+/// free-form human-readable line; `Glog`/`Json` are machine-ingestible and must not be
+/// wrapped in escape codes that would corrupt their syntax.
+fn should_colorize(format: LogFormat, use_color: bool) -> bool {
+    use_color && matches!(format, LogFormat::Copper)
+}
+
+/// Renders one rebuilt log line alongside the already-resolved message string.
+trait LogLineFormatter {
+    fn format(&self, entry: &CuLogEntry, msg: &str) -> String;
+}
+
+struct CopperLineFormat;
+
+impl LogLineFormatter for CopperLineFormat {
+    fn format(&self, entry: &CuLogEntry, msg: &str) -> String {
+        format!("Culog: [{}] {}", entry.time, msg)
+    }
+}
+
+struct GlogLineFormat;
+
+impl LogLineFormatter for GlogLineFormat {
+    /// `-` and `?:0` stand in for the severity letter and call-site file/line glog
+    /// lines normally carry: neither is available from `CuLogEntry`/
+    /// `read_interned_strings` yet (see the `cu29_intern_strs` note above the imports).
+    fn format(&self, entry: &CuLogEntry, msg: &str) -> String {
+        let severity = '-';
+        let (file, line_no) = ("?", 0u32);
+        let nanos: u64 = entry.time.into();
+        let (mmdd, hms_micros) = glog_timestamp(nanos);
+        let thread = std::thread::current()
+            .name()
+            .unwrap_or("main")
+            .to_string();
+        format!("{severity}{mmdd} {hms_micros} {thread} {file}:{line_no}] {msg}")
+    }
+}
+
+/// Renders a nanosecond timestamp as glog's `mmdd` date and `HH:MM:SS.micros` time.
+///
+/// `entry.time` is copper's robot clock, which counts nanoseconds from an
+/// arbitrary, per-run start rather than the Unix epoch, so the `mmdd` produced here is
+/// not a real calendar date — it's the same monotonic duration interpreted as if it
+/// were one, purely so the two counters (`mmdd` and `HH:MM:SS.micros`) line up with the
+/// glog format glog-consuming tools expect. Treat it as a relative offset, not a
+/// timestamp, until `cu29_clock` exposes a wall-clock/epoch reference to format against.
+fn glog_timestamp(nanos: u64) -> (String, String) {
+    let total_micros = nanos / 1_000;
+    let micros = total_micros % 1_000_000;
+    let total_secs = total_micros / 1_000_000;
+    let days_since_epoch = (total_secs / 86_400) as i64;
+    let secs_of_day = total_secs % 86_400;
+    let hours = secs_of_day / 3_600;
+    let mins = (secs_of_day / 60) % 60;
+    let secs = secs_of_day % 60;
+    let (_year, month, day) = civil_from_days(days_since_epoch);
+    (
+        format!("{month:02}{day:02}"),
+        format!("{hours:02}:{mins:02}:{secs:02}.{micros:06}"),
+    )
+}
+
+/// Howard Hinnant's days-since-epoch to (year, month, day) conversion, used to derive
+/// glog's `mmdd` date from a plain timestamp without pulling in a date/time dependency.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+struct JsonLineFormat;
+
+impl LogLineFormatter for JsonLineFormat {
+    /// `entry.params` holds bare values; the matching parameter names are interned
+    /// separately (see the `cu29_intern_strs` note above the imports) and aren't
+    /// resolvable from here, so params are keyed positionally rather than by name.
+    fn format(&self, entry: &CuLogEntry, msg: &str) -> String {
+        let mut params = Map::new();
+        for (i, value) in entry.params.iter().enumerate() {
+            if let Ok(v) = serde_json::to_value(value) {
+                params.insert(format!("param_{i}"), v);
+            }
+        }
+        serde_json::json!({
+            "time": entry.time.to_string(),
+            "msg_index": entry.msg_index,
+            "msg": msg,
+            "params": params,
+        })
+        .to_string()
+    }
+}
+
+/// A predicate applied while iterating a `.copper` file so callers can extract a subset
+/// of a large dump (by time window or message content) without post-processing.
+#[derive(Clone, Default)]
+pub struct LogFilter {
+    since: Option<u64>,
+    until: Option<u64>,
+    pattern: Option<Regex>,
+}
+
+impl LogFilter {
+    pub fn from_args(args: &LogFilterArgs) -> CuResult<Self> {
+        if args.min_severity.is_some() {
+            return Err(CuError::new_with_cause(
+                "--min-severity is unimplemented: CuLogEntry has no severity field, and cu29_log (which would need to add one) isn't vendored in this tree",
+                io::Error::new(io::ErrorKind::InvalidInput, "unsupported filter flag"),
+            ));
+        }
+        let pattern = args
+            .pattern
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|e| CuError::new_with_cause("Invalid --match regex", e))?;
+        Ok(Self {
+            since: args.since,
+            until: args.until,
+            pattern,
+        })
+    }
+
+    fn passes_time(&self, time: u64) -> bool {
+        if self.since.is_some_and(|since| time < since) {
+            return false;
+        }
+        if self.until.is_some_and(|until| time > until) {
+            return false;
+        }
+        true
+    }
+
+    /// Whether a structured log entry (already rebuilt into `line`) should be kept.
+    pub fn passes_log_entry(&self, entry: &CuLogEntry, line: &str) -> bool {
+        if !self.passes_time(entry.time.into()) {
+            return false;
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(line) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Whether `--since`/`--until` were requested. `ExtractCopperlist` rejects these up
+    /// front (see `run_cli`) since `CopperList` carries no clock to filter on.
+    pub fn has_time_window(&self) -> bool {
+        self.since.is_some() || self.until.is_some()
+    }
+
+    /// Whether `--match` was requested. `ExtractCopperlist` rejects this up front (see
+    /// `run_cli`) since `CopperList` carries no rebuilt message to match against.
+    pub fn has_pattern(&self) -> bool {
+        self.pattern.is_some()
+    }
+
+    /// Whether a `CopperList` should be kept. `CopperList` carries no severity, rebuilt
+    /// message, or timestamp of its own, so none of the filter dimensions apply here;
+    /// `run_cli` rejects `--since`/`--until`/`--match` for this subcommand instead of
+    /// silently dropping them.
+    pub fn passes_copperlist<P>(&self, _cl: &CopperList<P>) -> bool {
+        true
+    }
+}
+
 /// This is a generator for a main function to build a log extractor.
 #[derive(Parser)]
 #[command(author, version, about)]
 pub struct LogReaderCli {
     pub unifiedlog: PathBuf,
 
+    /// Colorize textlog output (Copper format only; see `LINE_ACCENT`).
+    #[arg(long, value_enum, default_value_t = ColorMode::Auto)]
+    pub color: ColorMode,
+
+    /// Keep reading as the file grows (tail -f style) instead of stopping at EOF.
+    #[arg(long)]
+    pub follow: bool,
+
+    #[command(flatten)]
+    pub filter: LogFilterArgs,
+
     #[command(subcommand)]
     pub command: Command,
 }
 
+/// Filtering flags shared by every subcommand so a subset of a large `.copper` file
+/// can be extracted without post-processing.
+#[derive(Parser, Clone, Default)]
+pub struct LogFilterArgs {
+    /// Only keep entries at or above this severity. Not implemented yet: `CuLogEntry`
+    /// carries no severity field, so `LogFilter::from_args` rejects this flag for now
+    /// instead of silently ignoring it.
+    #[arg(long)]
+    pub min_severity: Option<String>,
+    /// Only keep entries logged at or after this time (in the clock's native unit).
+    #[arg(long)]
+    pub since: Option<u64>,
+    /// Only keep entries logged at or before this time (in the clock's native unit).
+    #[arg(long)]
+    pub until: Option<u64>,
+    /// Only keep entries whose rebuilt log line matches this regex.
+    #[arg(long = "match")]
+    pub pattern: Option<String>,
+}
+
 #[derive(Subcommand)]
 pub enum Command {
     /// Extract logs
-    ExtractLog { log_index: PathBuf },
+    ExtractLog {
+        log_index: PathBuf,
+        /// Structured output layout for rebuilt log lines.
+        #[arg(short, long, value_enum, default_value_t = LogFormat::Copper)]
+        format: LogFormat,
+    },
     /// Extract copperlists
     ExtractCopperlist {
         #[arg(short, long, default_value_t = ExportFormat::Json)]
         export_format: ExportFormat,
+        /// Write the extracted copperlists to this file instead of stdout.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
 }
 
@@ -54,10 +376,11 @@ pub enum Command {
 /// It depends on the specific type of the CopperList payload that is determined at compile time from the configuration.
 pub fn run_cli<P>() -> CuResult<()>
 where
-    P: CopperListPayload,
+    P: CopperListPayload + Serialize,
 {
     let args = LogReaderCli::parse();
     let unifiedlog = args.unifiedlog;
+    let filter = LogFilter::from_args(&args.filter)?;
 
     let UnifiedLogger::Read(dl) = UnifiedLoggerBuilder::new()
         .file_path(&unifiedlog)
@@ -68,16 +391,32 @@ where
     };
 
     match args.command {
-        Command::ExtractLog { log_index } => {
+        Command::ExtractLog { log_index, format } => {
             let reader = UnifiedLoggerIOReader::new(dl, UnifiedLogType::StructuredLogLine);
-            textlog_dump(reader, &log_index)?;
+            textlog_dump(reader, &log_index, &filter, args.color, args.follow, format)?;
         }
-        Command::ExtractCopperlist { export_format } => {
-            println!("Extracting copperlists with format: {}", export_format);
-            let mut reader = UnifiedLoggerIOReader::new(dl, UnifiedLogType::CopperList);
-            let iter = copperlists_dump::<P>(&mut reader);
-            for entry in iter {
-                println!("{:#?}", entry);
+        Command::ExtractCopperlist {
+            export_format,
+            output,
+        } => {
+            if filter.has_time_window() || filter.has_pattern() {
+                return Err(CuError::new_with_cause(
+                    "--since/--until/--match are not supported for ExtractCopperlist: CopperList carries no clock or rebuilt message to filter on",
+                    io::Error::new(io::ErrorKind::InvalidInput, "unsupported filter for this subcommand"),
+                ));
+            }
+            eprintln!("Extracting copperlists with format: {}", export_format);
+            let reader = UnifiedLoggerIOReader::new(dl, UnifiedLogType::CopperList);
+            let iter = copperlists_dump::<P>(reader, filter, args.follow);
+            let out: Box<dyn Write> = match &output {
+                Some(path) => Box::new(BufWriter::new(File::create(path).map_err(|e| {
+                    CuError::new_with_cause("Failed to create output file", e)
+                })?)),
+                None => Box::new(io::stdout()),
+            };
+            match export_format {
+                ExportFormat::Json => export_copperlists_json(iter, out)?,
+                ExportFormat::Csv => export_copperlists_csv(iter, out)?,
             }
         }
     }
@@ -85,70 +424,221 @@ where
     Ok(())
 }
 
+/// How long to wait before retrying a read when `--follow` has caught up to the
+/// current end of the file.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Decodes bincode records out of `src`, buffering any trailing bytes that don't yet
+/// form a complete record. When `follow` is set, running out of currently available
+/// bytes is not terminal: it sleeps briefly and retries the read instead of stopping,
+/// so a live `.copper` file can be tailed as it grows, without corrupting a record that
+/// was only partially written when we hit the end.
+struct FollowingReader<R> {
+    src: R,
+    buf: Vec<u8>,
+    follow: bool,
+}
+
+impl<R: Read> FollowingReader<R> {
+    fn new(src: R, follow: bool) -> Self {
+        Self {
+            src,
+            buf: Vec::new(),
+            follow,
+        }
+    }
+
+    fn next<T: bincode::Decode<()>>(&mut self) -> CuResult<Option<T>> {
+        loop {
+            match bincode::decode_from_slice::<T, _>(&self.buf, standard()) {
+                Ok((value, consumed)) => {
+                    self.buf.drain(..consumed);
+                    return Ok(Some(value));
+                }
+                Err(DecodeError::UnexpectedEnd { .. }) => {
+                    if !self.fill_more()? {
+                        return Ok(None);
+                    }
+                }
+                Err(e) => return Err(CuError::new_with_cause("Error decoding record", e)),
+            }
+        }
+    }
+
+    /// Pulls more bytes into the buffer. Returns `false` only when not following and the
+    /// source is exhausted; when following, it sleeps and retries until bytes show up.
+    ///
+    /// Treating a following `Ok(0)` as "caught up, try again later" rather than permanent
+    /// EOF relies on `src` being a reader that can produce more bytes after reporting
+    /// `Ok(0)` once — i.e. a `UnifiedLoggerIOReader` polling the unified log for newly
+    /// flushed sections on each call, not a plain `File`/`Cursor`, for which `Ok(0)` really
+    /// does mean "nothing more, ever." `--follow` is only meaningful when `src` has that
+    /// polling contract.
+    fn fill_more(&mut self) -> CuResult<bool> {
+        let mut chunk = [0u8; 4096];
+        loop {
+            match self.src.read(&mut chunk) {
+                Ok(0) => {
+                    if !self.follow {
+                        return Ok(false);
+                    }
+                    sleep(FOLLOW_POLL_INTERVAL);
+                }
+                Ok(n) => {
+                    self.buf.extend_from_slice(&chunk[..n]);
+                    return Ok(true);
+                }
+                Err(e) => {
+                    return Err(CuError::new_with_cause("Error reading from unified log", e));
+                }
+            }
+        }
+    }
+}
+
 /// Extracts the copper lists from a binary representation.
 /// P is the Payload determined by the configuration of the application.
+/// Entries failing `filter` are skipped before being yielded. With `follow` set, reaching
+/// the current end of the stream blocks (polling) instead of ending iteration.
 pub fn copperlists_dump<P: CopperListPayload>(
-    mut src: impl Read,
+    src: impl Read,
+    filter: LogFilter,
+    follow: bool,
 ) -> impl Iterator<Item = CopperList<P>> {
-    std::iter::from_fn(move || {
-        let entry = decode_from_std_read::<CopperList<P>, _, _>(&mut src, standard());
-        match entry {
-            Ok(entry) => Some(entry),
-            Err(e) => match e {
-                DecodeError::UnexpectedEnd { .. } => return None,
-                DecodeError::Io { inner, additional } => {
-                    if inner.kind() == std::io::ErrorKind::UnexpectedEof {
-                        return None;
-                    } else {
-                        println!("Error {:?} additional:{}", inner, additional);
-                        return None;
-                    }
-                }
-                _ => {
-                    println!("Error {:?}", e);
-                    return None;
+    let mut reader = FollowingReader::new(src, follow);
+    std::iter::from_fn(move || loop {
+        match reader.next::<CopperList<P>>() {
+            Ok(Some(entry)) => {
+                if filter.passes_copperlist(&entry) {
+                    return Some(entry);
                 }
-            },
+                continue;
+            }
+            Ok(None) => return None,
+            Err(e) => {
+                println!("Error {:?}", e);
+                return None;
+            }
         }
     })
 }
 
+/// Flattens a `CopperList<P>` into a JSON object: the `id`/`state` metadata plus
+/// the payload fields, so arbitrary tuple/struct payloads serialize without a fixed schema.
+fn copperlist_row<P: Serialize>(cl: &CopperList<P>) -> Map<String, Value> {
+    let mut row = Map::new();
+    row.insert("id".to_string(), Value::from(cl.id));
+    row.insert("state".to_string(), Value::from(format!("{:?}", cl.state)));
+    match serde_json::to_value(&cl.payload).unwrap_or(Value::Null) {
+        Value::Object(fields) => row.extend(fields),
+        Value::Array(items) => {
+            for (i, item) in items.into_iter().enumerate() {
+                row.insert(format!("payload_{i}"), item);
+            }
+        }
+        other => {
+            row.insert("payload".to_string(), other);
+        }
+    }
+    row
+}
+
+/// Exports copperlists as newline-delimited JSON, one object per `CopperList<P>`.
+pub fn export_copperlists_json<P: Serialize>(
+    entries: impl Iterator<Item = CopperList<P>>,
+    mut out: impl Write,
+) -> CuResult<()> {
+    for entry in entries {
+        let line = serde_json::to_string(&copperlist_row(&entry))
+            .map_err(|e| CuError::new_with_cause("Failed to serialize copperlist to JSON", e))?;
+        writeln!(out, "{line}")
+            .map_err(|e| CuError::new_with_cause("Failed to write JSON output", e))?;
+    }
+    Ok(())
+}
+
+/// Exports copperlists as CSV, writing the header row once from the first entry's columns.
+pub fn export_copperlists_csv<P: Serialize>(
+    entries: impl Iterator<Item = CopperList<P>>,
+    out: impl Write,
+) -> CuResult<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    let mut columns: Option<Vec<String>> = None;
+    for entry in entries {
+        let row = copperlist_row(&entry);
+        let columns = columns.get_or_insert_with(|| {
+            // `id`/`state` lead regardless of how `Map` orders its keys.
+            let mut cols = vec!["id".to_string(), "state".to_string()];
+            cols.extend(row.keys().filter(|k| *k != "id" && *k != "state").cloned());
+            cols
+        });
+        if writer.position().byte() == 0 {
+            writer
+                .write_record(columns.iter())
+                .map_err(|e| CuError::new_with_cause("Failed to write CSV header", e))?;
+        }
+        let record = columns
+            .iter()
+            .map(|col| row.get(col).map(csv_cell).unwrap_or_default());
+        writer
+            .write_record(record)
+            .map_err(|e| CuError::new_with_cause("Failed to write CSV row", e))?;
+    }
+    writer
+        .flush()
+        .map_err(|e| CuError::new_with_cause("Failed to flush CSV output", e))?;
+    Ok(())
+}
+
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
 /// Full dump of the copper structured log from its binary representation.
 /// This rebuilds a textual log.
 /// src: the source of the log data
 /// index: the path to the index file (containing the interned strings constructed at build time)
-pub fn textlog_dump(mut src: impl Read, index: &Path) -> CuResult<()> {
+/// filter: a predicate skipping entries before they are printed
+/// color: whether to wrap each printed Copper-format line in `LINE_ACCENT`
+/// follow: keep polling for new entries instead of stopping at the current end of the stream
+/// format: the structured output layout to render each entry into
+pub fn textlog_dump(
+    src: impl Read,
+    index: &Path,
+    filter: &LogFilter,
+    color: ColorMode,
+    follow: bool,
+    format: LogFormat,
+) -> CuResult<()> {
     let all_strings = read_interned_strings(index)?;
+    let writer = TextLogWriter::new(color);
+    let formatter = format.formatter();
+    let mut reader = FollowingReader::new(src, follow);
     loop {
-        let entry = decode_from_std_read::<CuLogEntry, _, _>(&mut src, standard());
-
-        match entry {
-            Err(DecodeError::UnexpectedEnd { .. }) => return Ok(()),
-            Err(DecodeError::Io { inner, additional }) => {
-                if inner.kind() == std::io::ErrorKind::UnexpectedEof {
-                    return Ok(());
-                } else {
-                    println!("Error {:?} additional:{}", inner, additional);
-                    return Err(CuError::new_with_cause("Error reading log", inner));
-                }
-            }
-            Err(e) => {
-                println!("Error {:?}", e);
-                return Err(CuError::new_with_cause("Error reading log", e));
-            }
-            Ok(entry) => {
-                if entry.msg_index == 0 {
-                    break;
-                }
-
-                let result = rebuild_logline(&all_strings, &entry);
-                if result.is_err() {
-                    println!("Failed to rebuild log line: {:?}", result);
-                    continue;
-                }
-                println!("Culog: [{}] {}", entry.time, result.unwrap());
-            }
+        let entry = match reader.next::<CuLogEntry>()? {
+            Some(entry) => entry,
+            None => return Ok(()),
         };
+
+        if entry.msg_index == 0 {
+            break;
+        }
+
+        let result = rebuild_logline(&all_strings, &entry);
+        if result.is_err() {
+            println!("Failed to rebuild log line: {:?}", result);
+            continue;
+        }
+        let msg = result.unwrap();
+        if !filter.passes_log_entry(&entry, &msg) {
+            continue;
+        }
+        let formatted = formatter.format(&entry, &msg);
+        writer.write_line(&formatted, format);
     }
     Ok(())
 }
@@ -190,7 +680,15 @@ mod tests {
         let entry = CuLogEntry::new(3);
         let bytes = bincode::encode_to_vec(&entry, standard()).unwrap();
         let reader = Cursor::new(bytes.as_slice());
-        textlog_dump(reader, temp_path.as_path()).unwrap();
+        textlog_dump(
+            reader,
+            temp_path.as_path(),
+            &LogFilter::default(),
+            ColorMode::Never,
+            false,
+            LogFormat::Copper,
+        )
+        .unwrap();
     }
 
 
@@ -236,7 +734,15 @@ mod tests {
         };
         let reader = UnifiedLoggerIOReader::new(logger, UnifiedLogType::StructuredLogLine);
         let temp_dir = TempDir::new().unwrap();
-        textlog_dump(reader, Path::new(copy_stringindex_to_temp(&temp_dir).as_path())).expect("Failed to dump log");
+        textlog_dump(
+            reader,
+            Path::new(copy_stringindex_to_temp(&temp_dir).as_path()),
+            &LogFilter::default(),
+            ColorMode::Never,
+            false,
+            LogFormat::Copper,
+        )
+        .expect("Failed to dump log");
     }
 
     // This is normally generated at compile time in CuPayload.
@@ -257,10 +763,182 @@ mod tests {
 
         let reader = Cursor::new(data);
 
-        let mut iter = copperlists_dump::<MyCuPayload>(reader);
+        let mut iter = copperlists_dump::<MyCuPayload>(reader, LogFilter::default(), false);
         assert_eq!(iter.next().unwrap().payload, (1, 2, 3.0));
         assert_eq!(iter.next().unwrap().payload, (2, 3, 4.0));
         assert_eq!(iter.next().unwrap().payload, (3, 4, 5.0));
         assert_eq!(iter.next().unwrap().payload, (4, 5, 6.0));
     }
+
+    fn make_copperlist_data(payloads: &[MyCuPayload]) -> Vec<u8> {
+        let mut data = vec![0u8; 10000];
+        let mut offset: usize = 0;
+        for pl in payloads.iter() {
+            let cl = CopperList::<MyCuPayload>::new(1, *pl);
+            offset +=
+                encode_into_slice(&cl, &mut data.as_mut_slice()[offset..], standard()).unwrap();
+        }
+        data.truncate(offset);
+        data
+    }
+
+    #[test]
+    fn test_export_copperlists_json() {
+        let data = make_copperlist_data(&[(1, 2, 3.0), (2, 3, 4.0)]);
+        let iter = copperlists_dump::<MyCuPayload>(Cursor::new(data), LogFilter::default(), false);
+        let mut out = Vec::new();
+        export_copperlists_json(iter, &mut out).expect("Failed to export JSON");
+        let output = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["payload_0"], 1);
+        assert_eq!(first["payload_1"], 2);
+    }
+
+    #[test]
+    fn test_export_copperlists_csv() {
+        let data = make_copperlist_data(&[(1, 2, 3.0), (2, 3, 4.0)]);
+        let iter = copperlists_dump::<MyCuPayload>(Cursor::new(data), LogFilter::default(), false);
+        let mut out = Vec::new();
+        export_copperlists_csv(iter, &mut out).expect("Failed to export CSV");
+        let output = String::from_utf8(out).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(lines.next().unwrap(), "id,state,payload_0,payload_1,payload_2");
+        assert!(lines.next().unwrap().starts_with("1,"));
+        assert!(lines.next().unwrap().starts_with("1,"));
+    }
+
+    #[test]
+    fn test_passes_time() {
+        let filter = LogFilter {
+            since: Some(100),
+            until: Some(200),
+            ..Default::default()
+        };
+        assert!(filter.passes_time(150));
+        assert!(!filter.passes_time(50));
+        assert!(!filter.passes_time(250));
+        assert!(LogFilter::default().passes_time(0));
+    }
+
+    #[test]
+    fn test_has_time_window() {
+        assert!(!LogFilter::default().has_time_window());
+        let since_only = LogFilter {
+            since: Some(1),
+            ..Default::default()
+        };
+        assert!(since_only.has_time_window());
+        let until_only = LogFilter {
+            until: Some(1),
+            ..Default::default()
+        };
+        assert!(until_only.has_time_window());
+    }
+
+    #[test]
+    fn test_from_args_rejects_min_severity() {
+        let args = LogFilterArgs {
+            min_severity: Some("warning".to_string()),
+            since: None,
+            until: None,
+            pattern: None,
+        };
+        assert!(LogFilter::from_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_passes_log_entry_pattern() {
+        let entry = CuLogEntry::new(0);
+        let filter = LogFilter::from_args(&LogFilterArgs {
+            min_severity: None,
+            since: None,
+            until: None,
+            pattern: Some("hello".to_string()),
+        })
+        .unwrap();
+        assert!(filter.passes_log_entry(&entry, "say hello world"));
+        assert!(!filter.passes_log_entry(&entry, "say goodbye"));
+    }
+
+    #[test]
+    fn test_has_pattern() {
+        assert!(!LogFilter::default().has_pattern());
+        let with_pattern = LogFilter::from_args(&LogFilterArgs {
+            min_severity: None,
+            since: None,
+            until: None,
+            pattern: Some("hello".to_string()),
+        })
+        .unwrap();
+        assert!(with_pattern.has_pattern());
+    }
+
+    /// A `Read` that only ever hands back `chunk_size` bytes at a time, to exercise
+    /// `FollowingReader`'s buffering across reads that split a record mid-decode.
+    struct ChunkedReader {
+        data: Vec<u8>,
+        pos: usize,
+        chunk_size: usize,
+    }
+
+    impl Read for ChunkedReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let remaining = self.data.len() - self.pos;
+            if remaining == 0 {
+                return Ok(0);
+            }
+            let n = remaining.min(self.chunk_size).min(buf.len());
+            buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_following_reader_resumes_across_partial_reads() {
+        let data = make_copperlist_data(&[(1, 2, 3.0), (2, 3, 4.0)]);
+        let reader = ChunkedReader {
+            data,
+            pos: 0,
+            chunk_size: 3,
+        };
+        let mut following = FollowingReader::new(reader, false);
+        let first: CopperList<MyCuPayload> = following.next().unwrap().unwrap();
+        assert_eq!(first.payload, (1, 2, 3.0));
+        let second: CopperList<MyCuPayload> = following.next().unwrap().unwrap();
+        assert_eq!(second.payload, (2, 3, 4.0));
+        assert!(following
+            .next::<CopperList<MyCuPayload>>()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_civil_from_days_epoch() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(31), (1970, 2, 1));
+    }
+
+    #[test]
+    fn test_glog_timestamp() {
+        assert_eq!(
+            glog_timestamp(0),
+            ("0101".to_string(), "00:00:00.000000".to_string())
+        );
+        // 90_061s = 1 day, 1 hour, 1 minute, 1 second past the reference point.
+        assert_eq!(
+            glog_timestamp(90_061_000_000_000),
+            ("0102".to_string(), "01:01:01.000000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_should_colorize() {
+        assert!(should_colorize(LogFormat::Copper, true));
+        assert!(!should_colorize(LogFormat::Copper, false));
+        assert!(!should_colorize(LogFormat::Glog, true));
+        assert!(!should_colorize(LogFormat::Json, true));
+    }
 }